@@ -8,13 +8,27 @@ mod token;
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub enum Ix {
+    /// Creates the mint PDA and initializes it. When `mint_authority` is
+    /// `None`, the mint PDA becomes its own mint authority, making this
+    /// program the sole minter. Passing `Some(pubkey)` hands authority to an
+    /// external key instead — e.g. an SPL `Multisig` account — at the cost
+    /// of this program no longer being able to mint via `MintTo`.
+    ///
+    /// Also creates the mint-gate PDA, capturing `gate_authority` as the only
+    /// key `MintTo` will accept as `gate_signer` for this mint.
     /// Accounts:
     /// 0. [signer,writable] payer
+    /// 1. [writable]        mint
     /// 2. []                system_program
+    /// 3. []                token_program (classic spl_token or Token-2022)
+    /// 4. [writable]        mint_gate
     CreateAndInitMint {
-        mint_authority: Pubkey,
+        mint_authority: Option<Pubkey>,
+        freeze_authority: Option<Pubkey>,
         decimals: u8,
         bump: u8,
+        gate_authority: Pubkey,
+        gate_bump: u8,
     },
 
     /// Accounts:
@@ -26,13 +40,93 @@ pub enum Ix {
     /// 5. []       system_program
     CreateAtaFor,
 
-    /// Burn `amount_ui` whole tokens (UI units, not base units)
+    /// Burn `amount_ui` whole tokens (UI units, not base units). The burn
+    /// authority may be a single signer or an SPL `Multisig` account; in the
+    /// latter case, the trailing signer accounts must cover at least `m` of
+    /// the multisig's signers.
     /// Accounts:
-    /// 0. []       mint
-    /// 1. [signer] owner (authority of token account)
+    /// 0. [writable] mint
+    /// 1. [signer?]  authority (owner of the token account; single key or Multisig;
+    ///               must sign unless it is a Multisig)
     /// 2. [writable] token_account (owner's ATA)
-    /// 3. []       spl_token program (Tokenkeg…)
+    /// 3. []         token program (classic spl_token or Token-2022)
+    /// 4.. [signer]  multisig signers (only when `authority` is a Multisig)
     BurnUserTokens { amount_ui: u64 },
+
+    /// Freeze a user's token account. `bump` identifies the mint PDA seeds so
+    /// the handler can tell whether the freeze authority is the PDA itself
+    /// (signed via `invoke_signed`) or an external signer.
+    /// Accounts:
+    /// 0. []         mint
+    /// 1. [writable] token_account (owner's ATA)
+    /// 2. [signer?]  freeze_authority (signer unless it is the mint PDA)
+    /// 3. []         token program (classic spl_token or Token-2022)
+    FreezeUserAccount { bump: u8 },
+
+    /// Thaw a previously frozen token account. Same account layout as
+    /// `FreezeUserAccount`.
+    ThawUserAccount { bump: u8 },
+
+    /// Mint `amount_ui` whole tokens (UI units, not base units) to a
+    /// destination ATA, with the mint PDA signing as mint authority.
+    /// `gate_signer` must match the authority captured for this mint at
+    /// `CreateAndInitMint` time.
+    /// Accounts:
+    /// 0. [writable] mint
+    /// 1. [writable] dest_ata
+    /// 2. [signer]   gate_signer (must match the mint_gate authority)
+    /// 3. []         mint_gate
+    /// 4. []         token program (classic spl_token or Token-2022)
+    MintTo {
+        amount_ui: u64,
+        bump: u8,
+        gate_bump: u8,
+    },
+
+    /// Creates the mint PDA exactly like `CreateAndInitMint`, but forces
+    /// `decimals = 0`, making it a one-shot NFT mint once sealed by
+    /// `MintOneAndSeal`. Also creates the mint-gate PDA, capturing
+    /// `gate_authority` as the only key `MintOneAndSeal` will accept as
+    /// `gate_signer` for this mint.
+    /// Accounts: same as `CreateAndInitMint`.
+    CreateNftMint {
+        bump: u8,
+        gate_authority: Pubkey,
+        gate_bump: u8,
+    },
+
+    /// Mint exactly one base unit to a destination ATA, then permanently
+    /// revoke the mint authority so supply is fixed at one. `gate_signer`
+    /// must match the authority captured for this mint at `CreateNftMint`
+    /// time — without this, anyone could race the intended recipient and
+    /// steal the one-of-one by submitting their own `dest_ata` first.
+    /// Accounts:
+    /// 0. [writable] mint
+    /// 1. [writable] dest_ata
+    /// 2. [signer]   gate_signer (must match the mint_gate authority)
+    /// 3. []         mint_gate
+    /// 4. []         token program (classic spl_token or Token-2022)
+    MintOneAndSeal { bump: u8, gate_bump: u8 },
+
+    /// Runs the `CreateAndInitMint` flow (always with the mint PDA as its
+    /// own authority) and then CPIs into the Metaplex Token Metadata program
+    /// to create a Metadata PDA naming the mint, so wallets can display it.
+    /// No mint-gate PDA is created, so `MintTo` cannot be used on this mint.
+    /// Accounts:
+    /// 0. [signer,writable] payer
+    /// 1. [writable]         mint PDA
+    /// 2. []                 system_program
+    /// 3. []                 token program (classic spl_token or Token-2022)
+    /// 4. []                 metadata_program (Metaplex Token Metadata)
+    /// 5. [writable]         metadata PDA (["metadata", metadata_program, mint])
+    /// 6. []                 rent sysvar
+    CreateMintWithMetadata {
+        name: String,
+        symbol: String,
+        uri: String,
+        decimals: u8,
+        bump: u8,
+    },
 }
 
 impl Ix {
@@ -54,14 +148,68 @@ pub fn process_instruction(
     match ix {
         Ix::CreateAndInitMint {
             mint_authority,
+            freeze_authority,
             decimals,
             bump,
+            gate_authority,
+            gate_bump,
         } => {
             let seeds: &[&[u8]] = &[b"MINT", &[bump]];
-            token::create_and_init_mint(program_id, accounts, &mint_authority, seeds, decimals)
+            token::create_and_init_mint(
+                program_id,
+                accounts,
+                mint_authority.as_ref(),
+                freeze_authority.as_ref(),
+                seeds,
+                decimals,
+            )?;
+            let gate_seeds: &[&[u8]] = &[b"MINT_GATE", &[gate_bump]];
+            token::init_mint_gate(program_id, accounts, gate_seeds, &gate_authority)
         }
         Ix::CreateAtaFor => token::create_ata_for(accounts),
         Ix::BurnUserTokens { amount_ui } => token::burn_user_tokens(accounts, amount_ui),
+        Ix::FreezeUserAccount { bump } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            token::freeze_user_account(program_id, accounts, seeds)
+        }
+        Ix::ThawUserAccount { bump } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            token::thaw_user_account(program_id, accounts, seeds)
+        }
+        Ix::MintTo {
+            amount_ui,
+            bump,
+            gate_bump,
+        } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            let gate_seeds: &[&[u8]] = &[b"MINT_GATE", &[gate_bump]];
+            token::mint_to(program_id, accounts, seeds, gate_seeds, amount_ui)
+        }
+        Ix::CreateNftMint {
+            bump,
+            gate_authority,
+            gate_bump,
+        } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            token::create_nft_mint(program_id, accounts, seeds)?;
+            let gate_seeds: &[&[u8]] = &[b"MINT_GATE", &[gate_bump]];
+            token::init_mint_gate(program_id, accounts, gate_seeds, &gate_authority)
+        }
+        Ix::MintOneAndSeal { bump, gate_bump } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            let gate_seeds: &[&[u8]] = &[b"MINT_GATE", &[gate_bump]];
+            token::mint_one_and_seal(program_id, accounts, seeds, gate_seeds)
+        }
+        Ix::CreateMintWithMetadata {
+            name,
+            symbol,
+            uri,
+            decimals,
+            bump,
+        } => {
+            let seeds: &[&[u8]] = &[b"MINT", &[bump]];
+            token::create_mint_with_metadata(program_id, accounts, seeds, decimals, name, symbol, uri)
+        }
     }
 }
 
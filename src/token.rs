@@ -8,25 +8,36 @@ use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
 use spl_associated_token_account as ata;
-use spl_token::id as spl_token_id;
 use spl_token::instruction as token_instruction;
-use spl_token::state::{Account as SplAccount, Mint as SplMint};
+use spl_token::instruction::MAX_SIGNERS;
+use spl_token::state::{Account as SplAccount, Mint as SplMint, Multisig};
 
-pub fn create_and_init_mint(
+/// Confirm `token_program` is either the classic SPL Token program or
+/// Token-2022, and return its id. Every CPI in this module is built against
+/// whichever of the two was actually passed in, so mints can opt into
+/// Token-2022 (and its extensions) without forking the program.
+fn resolve_token_program_id(token_program: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    if *token_program.key == spl_token::id() || *token_program.key == spl_token_2022::id() {
+        Ok(*token_program.key)
+    } else {
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+/// Creates the mint PDA account and initializes it. Shared by
+/// `create_and_init_mint` and `create_mint_with_metadata`, which both start
+/// with the same payer/mint/system_program/token_program accounts.
+fn init_mint_pda(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    mint_authority: &Pubkey,
+    payer: &AccountInfo,
+    token_mint: &AccountInfo,
+    system_program: &AccountInfo,
+    token_program: &AccountInfo,
+    mint_authority: Option<&Pubkey>,
+    freeze_authority: Option<&Pubkey>,
     mint_seeds: &[&[u8]],
     token_decimals: u8,
-) -> ProgramResult {
-    let acc_iter = &mut accounts.iter();
-
-    //payer (signer), mint (writable), system program
-    let payer = next_account_info(acc_iter)?;
-    let token_mint = next_account_info(acc_iter)?;
-    let system_program = next_account_info(acc_iter)?;
-    let token_program = next_account_info(acc_iter)?;
-
+) -> Result<Pubkey, ProgramError> {
     if !payer.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
@@ -42,6 +53,8 @@ pub fn create_and_init_mint(
         return Err(ProgramError::InvalidSeeds);
     }
 
+    let token_program_id = resolve_token_program_id(token_program)?;
+
     let space = SplMint::LEN as u64;
     let lamports = Rent::get()?.minimum_balance(space as usize);
 
@@ -51,7 +64,7 @@ pub fn create_and_init_mint(
             &token_mint.key,
             lamports,
             space,
-            &spl_token_id(),
+            &token_program_id,
         ),
         &[
             token_program.clone(),
@@ -62,15 +75,215 @@ pub fn create_and_init_mint(
         &[mint_seeds],
     )?;
 
+    // Default to the mint PDA as its own mint authority, so this program is
+    // the sole minter and all supply changes go through `mint_to`. Callers
+    // may instead hand authority to an external key (e.g. a `Multisig`).
+    let resolved_mint_authority = mint_authority.unwrap_or(token_mint.key);
     let initialize_ix = token_instruction::initialize_mint2(
-        &spl_token_id(),
+        &token_program_id,
         token_mint.key,
+        resolved_mint_authority,
+        freeze_authority,
+        token_decimals,
+    )?;
+
+    invoke(&initialize_ix, &[token_mint.clone()])?;
+
+    Ok(token_program_id)
+}
+
+pub fn create_and_init_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_authority: Option<&Pubkey>,
+    freeze_authority: Option<&Pubkey>,
+    mint_seeds: &[&[u8]],
+    token_decimals: u8,
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    //payer (signer), mint (writable), system program
+    let payer = next_account_info(acc_iter)?;
+    let token_mint = next_account_info(acc_iter)?;
+    let system_program = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+
+    init_mint_pda(
+        program_id,
+        payer,
+        token_mint,
+        system_program,
+        token_program,
         mint_authority,
+        freeze_authority,
+        mint_seeds,
+        token_decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Runs the same mint-creation flow as `create_and_init_mint`, then CPIs
+/// into the Metaplex Token Metadata program to attach a name/symbol/uri to
+/// it. The mint PDA signs both CPIs, acting as mint authority and metadata
+/// update authority.
+pub fn create_mint_with_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+    token_decimals: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    let payer = next_account_info(acc_iter)?;
+    let token_mint = next_account_info(acc_iter)?;
+    let system_program = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+    let metadata_program = next_account_info(acc_iter)?;
+    let metadata_pda = next_account_info(acc_iter)?;
+    let rent_sysvar = next_account_info(acc_iter)?;
+
+    init_mint_pda(
+        program_id,
+        payer,
+        token_mint,
+        system_program,
+        token_program,
+        None,
         None,
+        mint_seeds,
         token_decimals,
     )?;
 
-    invoke(&initialize_ix, &[token_mint.clone()])?;
+    // Ensure the passed metadata account is exactly the PDA Metaplex expects
+    // for this mint before CPIing into it.
+    let expected_metadata_pda = Pubkey::find_program_address(
+        &[b"metadata", metadata_program.key.as_ref(), token_mint.key.as_ref()],
+        metadata_program.key,
+    )
+    .0;
+    if *metadata_pda.key != expected_metadata_pda {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let create_metadata_ix = mpl_token_metadata::instruction::create_metadata_accounts_v3(
+        *metadata_program.key,
+        *metadata_pda.key,
+        *token_mint.key,
+        *token_mint.key,
+        *payer.key,
+        *token_mint.key,
+        name,
+        symbol,
+        uri,
+        None,
+        0,
+        true,
+        true,
+        None,
+        None,
+        None,
+    );
+
+    invoke_signed(
+        &create_metadata_ix,
+        &[
+            metadata_pda.clone(),
+            token_mint.clone(),
+            token_mint.clone(),
+            payer.clone(),
+            token_mint.clone(),
+            system_program.clone(),
+            rent_sysvar.clone(),
+        ],
+        &[mint_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Same as `create_and_init_mint`, but always forces `decimals = 0` so the
+/// mint is suitable for a one-shot NFT sealed by `mint_one_and_seal`.
+pub fn create_nft_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+) -> ProgramResult {
+    create_and_init_mint(program_id, accounts, None, None, mint_seeds, 0)
+}
+
+/// Mint exactly one base unit to `dest_ata` and then permanently revoke the
+/// mint authority, sealing supply at one. Both CPIs are signed by the mint
+/// PDA via `mint_seeds`. `gate_signer` must match the authority stored in
+/// `mint_gate` (captured at `CreateNftMint` time, the same gate `mint_to`
+/// checks) and must sign — otherwise anyone could race the legitimate
+/// recipient and steal the one-of-one for an arbitrary `dest_ata`.
+pub fn mint_one_and_seal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+    gate_seeds: &[&[u8]],
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    // 0 mint, 1 dest_ata, 2 gate_signer, 3 mint_gate, 4 token_program
+    let mint_account = next_account_info(acc_iter)?;
+    let dest_ata = next_account_info(acc_iter)?;
+    let gate_signer = next_account_info(acc_iter)?;
+    let mint_gate = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+
+    if *gate_signer.key != read_mint_gate_authority(program_id, mint_gate, gate_seeds)? {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !gate_signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let token_program_id = resolve_token_program_id(token_program)?;
+
+    let expected_mint_pda = Pubkey::create_program_address(mint_seeds, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if *mint_account.key != expected_mint_pda {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mint = SplMint::unpack(&mint_account.try_borrow_data()?)?;
+
+    let mint_to_ix = token_instruction::mint_to_checked(
+        &token_program_id,
+        mint_account.key,
+        dest_ata.key,
+        mint_account.key,
+        &[],
+        1,
+        mint.decimals,
+    )?;
+
+    invoke_signed(
+        &mint_to_ix,
+        &[mint_account.clone(), dest_ata.clone(), mint_account.clone()],
+        &[mint_seeds],
+    )?;
+
+    let seal_ix = token_instruction::set_authority(
+        &token_program_id,
+        mint_account.key,
+        None,
+        token_instruction::AuthorityType::MintTokens,
+        mint_account.key,
+        &[],
+    )?;
+
+    invoke_signed(
+        &seal_ix,
+        &[mint_account.clone(), mint_account.clone()],
+        &[mint_seeds],
+    )?;
 
     Ok(())
 }
@@ -85,11 +298,13 @@ pub fn create_ata_for(accounts: &[AccountInfo]) -> ProgramResult {
     let token_program = next_account_info(acc_iter)?;
     let system_program = next_account_info(acc_iter)?;
 
+    let token_program_id = resolve_token_program_id(token_program)?;
+
     // Derive the expected ATA address and compare
     let expected_ata = ata::get_associated_token_address_with_program_id(
         owner.key,
         token_mint.key,
-        &spl_token_id(),
+        &token_program_id,
     );
 
     // Sanity check: is the passed ATA the one we expect?
@@ -107,7 +322,7 @@ pub fn create_ata_for(accounts: &[AccountInfo]) -> ProgramResult {
         payer.key,
         owner.key,
         token_mint.key,
-        &spl_token_id(),
+        &token_program_id,
     );
 
     // Invoke the ATA creation instruction
@@ -129,18 +344,19 @@ pub fn create_ata_for(accounts: &[AccountInfo]) -> ProgramResult {
 pub fn burn_user_tokens(accounts: &[AccountInfo], amount_ui: u64) -> ProgramResult {
     let acc_iter = &mut accounts.iter();
 
-    // 0 mint, 1 owner(signer), 2 token_account(ATA), 3 token_program
+    // 0 mint, 1 authority (single key or Multisig), 2 token_account(ATA), 3 token_program
     let mint_account = next_account_info(acc_iter)?;
-    let owner_account = next_account_info(acc_iter)?; // authority, must sign
+    let authority_account = next_account_info(acc_iter)?;
     let ata_token_acc = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+    // 4.. multisig signers, only consumed when `authority_account` is a Multisig
+    let multisig_signers: Vec<&AccountInfo> = acc_iter.collect();
 
-    if !owner_account.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let token_program_id = resolve_token_program_id(token_program)?;
 
-    // Sanity: token account belongs to owner and matches mint
+    // Sanity: token account belongs to the authority and matches mint
     let token_account = SplAccount::unpack(&ata_token_acc.try_borrow_data()?)?;
-    if token_account.mint != *mint_account.key || token_account.owner != *owner_account.key {
+    if token_account.mint != *mint_account.key || token_account.owner != *authority_account.key {
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -151,25 +367,271 @@ pub fn burn_user_tokens(accounts: &[AccountInfo], amount_ui: u64) -> ProgramResu
         .checked_mul(10u64.pow(decimals as u32))
         .ok_or(ProgramError::InvalidArgument)?;
 
-    // Burn (checked) — accounts: [token_account, mint, authority]
+    let is_multisig = *authority_account.owner == token_program_id
+        && authority_account.data_len() == Multisig::LEN;
+
+    let mut cpi_accounts = vec![ata_token_acc.clone(), mint_account.clone(), authority_account.clone()];
+    let signer_pubkeys: Vec<Pubkey>;
+
+    if is_multisig {
+        let multisig = Multisig::unpack(&authority_account.try_borrow_data()?)?;
+
+        if multisig_signers.len() > MAX_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Count matched *positions* in `multisig.signers`, not raw hits —
+        // otherwise passing the same real signer account twice would count
+        // its one signature twice toward `m`.
+        let present = multisig.signers[..multisig.n as usize]
+            .iter()
+            .filter(|expected| {
+                multisig_signers
+                    .iter()
+                    .any(|signer| signer.is_signer && signer.key == *expected)
+            })
+            .count();
+        if (present as u8) < multisig.m {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        signer_pubkeys = multisig_signers.iter().map(|s| *s.key).collect();
+        cpi_accounts.extend(multisig_signers.iter().map(|s| (*s).clone()));
+    } else {
+        if !authority_account.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        signer_pubkeys = Vec::new();
+    }
+
+    let signer_pubkey_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+
+    // Burn (checked) — accounts: [token_account, mint, authority, ...signers]
     let burn_ix = token_instruction::burn_checked(
-        &spl_token_id(),
+        &token_program_id,
         ata_token_acc.key,
         mint_account.key,
-        owner_account.key,
+        authority_account.key,
+        &signer_pubkey_refs,
+        amount_base,
+        decimals,
+    )?;
+
+    invoke(&burn_ix, &cpi_accounts)?;
+
+    Ok(())
+}
+
+/// Creates the small PDA-owned account that records the key authorized to
+/// invoke `mint_to` (and `mint_one_and_seal`) for this mint. The authority is
+/// captured once here, at `CreateAndInitMint`/`CreateNftMint` time, rather
+/// than baked into the program as a shared admin key — each deployment (and
+/// each mint it creates) gets its own gate.
+pub fn init_mint_gate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    gate_seeds: &[&[u8]],
+    gate_authority: &Pubkey,
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    // 0 payer, 1 mint, 2 system_program, 3 token_program, 4 mint_gate
+    let payer = next_account_info(acc_iter)?;
+    let _mint = next_account_info(acc_iter)?;
+    let system_program = next_account_info(acc_iter)?;
+    let _token_program = next_account_info(acc_iter)?;
+    let mint_gate = next_account_info(acc_iter)?;
+
+    let expected = Pubkey::create_program_address(gate_seeds, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if *mint_gate.key != expected {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let space = 32u64;
+    let lamports = Rent::get()?.minimum_balance(space as usize);
+
+    invoke_signed(
+        &solana_system_interface::instruction::create_account(
+            payer.key,
+            mint_gate.key,
+            lamports,
+            space,
+            program_id,
+        ),
+        &[payer.clone(), mint_gate.clone(), system_program.clone()],
+        &[gate_seeds],
+    )?;
+
+    mint_gate
+        .try_borrow_mut_data()?
+        .copy_from_slice(gate_authority.as_ref());
+
+    Ok(())
+}
+
+/// Reads the authority pubkey stored by `init_mint_gate`, checking that
+/// `mint_gate` is the PDA these `gate_seeds` derive to and that it's owned by
+/// this program (so it can only have been written by `init_mint_gate`).
+fn read_mint_gate_authority(
+    program_id: &Pubkey,
+    mint_gate: &AccountInfo,
+    gate_seeds: &[&[u8]],
+) -> Result<Pubkey, ProgramError> {
+    let expected = Pubkey::create_program_address(gate_seeds, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if *mint_gate.key != expected || *mint_gate.owner != *program_id {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let data = mint_gate.try_borrow_data()?;
+    let stored: [u8; 32] = data[..32]
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok(Pubkey::new_from_array(stored))
+}
+
+/// Mint `amount_ui` whole tokens (UI units) to `dest_ata`, with the mint PDA
+/// signing as mint authority. `gate_signer` must match the authority stored
+/// in `mint_gate` (captured once at `CreateAndInitMint` time via
+/// `init_mint_gate`) and must sign the transaction — anyone else, signer or
+/// not, is rejected.
+pub fn mint_to(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+    gate_seeds: &[&[u8]],
+    amount_ui: u64,
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    // 0 mint, 1 dest_ata, 2 gate_signer, 3 mint_gate, 4 token_program
+    let mint_account = next_account_info(acc_iter)?;
+    let dest_ata = next_account_info(acc_iter)?;
+    let gate_signer = next_account_info(acc_iter)?;
+    let mint_gate = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+
+    if *gate_signer.key != read_mint_gate_authority(program_id, mint_gate, gate_seeds)? {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !gate_signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let token_program_id = resolve_token_program_id(token_program)?;
+
+    // Ensure the passed mint is exactly the PDA we expect for these seeds —
+    // it is both the mint and, per `create_and_init_mint`, its own authority.
+    let expected_mint_pda = Pubkey::create_program_address(mint_seeds, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    if *mint_account.key != expected_mint_pda {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Derive base units using mint decimals
+    let mint = SplMint::unpack(&mint_account.try_borrow_data()?)?;
+    let decimals = mint.decimals;
+    let amount_base = amount_ui
+        .checked_mul(10u64.pow(decimals as u32))
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    let mint_to_ix = token_instruction::mint_to_checked(
+        &token_program_id,
+        mint_account.key,
+        dest_ata.key,
+        mint_account.key,
         &[],
         amount_base,
         decimals,
     )?;
 
-    invoke(
-        &burn_ix,
-        &[
-            ata_token_acc.clone(),
-            mint_account.clone(),
-            owner_account.clone(),
-        ],
+    invoke_signed(
+        &mint_to_ix,
+        &[mint_account.clone(), dest_ata.clone(), mint_account.clone()],
+        &[mint_seeds],
     )?;
 
     Ok(())
 }
+
+pub fn freeze_user_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+) -> ProgramResult {
+    set_account_freeze_state(program_id, accounts, mint_seeds, true)
+}
+
+pub fn thaw_user_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+) -> ProgramResult {
+    set_account_freeze_state(program_id, accounts, mint_seeds, false)
+}
+
+fn set_account_freeze_state(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint_seeds: &[&[u8]],
+    freeze: bool,
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    // 0 mint, 1 token_account(ATA), 2 freeze_authority, 3 token_program
+    let mint_account = next_account_info(acc_iter)?;
+    let ata_token_acc = next_account_info(acc_iter)?;
+    let freeze_authority = next_account_info(acc_iter)?;
+    let token_program = next_account_info(acc_iter)?;
+
+    let token_program_id = resolve_token_program_id(token_program)?;
+
+    // Sanity: token account matches mint
+    let token_account = SplAccount::unpack(&ata_token_acc.try_borrow_data()?)?;
+    if token_account.mint != *mint_account.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let freeze_ix = if freeze {
+        token_instruction::freeze_account(
+            &token_program_id,
+            ata_token_acc.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[],
+        )?
+    } else {
+        token_instruction::thaw_account(
+            &token_program_id,
+            ata_token_acc.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[],
+        )?
+    };
+
+    let cpi_accounts = [
+        ata_token_acc.clone(),
+        mint_account.clone(),
+        freeze_authority.clone(),
+        token_program.clone(),
+    ];
+
+    // The mint PDA can itself be the freeze authority, in which case it
+    // signs via `invoke_signed`; otherwise the authority must sign normally.
+    let expected_mint_pda = Pubkey::create_program_address(mint_seeds, program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+
+    if *freeze_authority.key == expected_mint_pda {
+        invoke_signed(&freeze_ix, &cpi_accounts, &[mint_seeds])?;
+    } else {
+        if !freeze_authority.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        invoke(&freeze_ix, &cpi_accounts)?;
+    }
+
+    Ok(())
+}
@@ -1,24 +1,29 @@
 // tests/token_tests.rs
 
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
-use solana_program::sysvar;
+use solana_program::rent::Rent;
+use solana_program::sysvar::{self, Sysvar};
 use solana_program_test::{processor, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    system_program,
+    system_instruction, system_program,
     transaction::Transaction,
 };
 
 use spl_associated_token_account as ata;
-use spl_token::{self, state::{Account as SplAccount, Mint as SplMint}};
+use spl_token::{self, state::{Account as SplAccount, AccountState, Mint as SplMint, Multisig}};
 
 use token_mints::Ix; // your program’s instruction enum with .pack()
 
 // ---------- Test harness ----------
 
-fn program_test() -> (ProgramTest, Pubkey) {
+fn program_test(token_program_id: Pubkey) -> (ProgramTest, Pubkey) {
     let program_id = Pubkey::new_unique();
 
     let mut pt = ProgramTest::new(
@@ -27,12 +32,20 @@ fn program_test() -> (ProgramTest, Pubkey) {
         processor!(token_mints::process_instruction),
     );
 
-    // CPI targets
-    pt.add_program(
-        "spl_token",
-        spl_token::id(),
-        processor!(spl_token::processor::Processor::process),
-    );
+    // CPI targets: register whichever token program the test is exercising.
+    if token_program_id == spl_token_2022::id() {
+        pt.add_program(
+            "spl_token_2022",
+            spl_token_2022::id(),
+            processor!(spl_token_2022::processor::Processor::process),
+        );
+    } else {
+        pt.add_program(
+            "spl_token",
+            spl_token::id(),
+            processor!(spl_token::processor::Processor::process),
+        );
+    }
     pt.add_program(
         "spl_associated_token_account",
         ata::id(),
@@ -46,6 +59,10 @@ fn find_mint_pda(program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"MINT"], program_id)
 }
 
+fn find_mint_gate_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"MINT_GATE"], program_id)
+}
+
 // ---------- Instruction builders (use Ix::pack()) ----------
 
 fn ix_create_and_init_mint(
@@ -54,16 +71,215 @@ fn ix_create_and_init_mint(
     mint_pda: Pubkey,
     decimals: u8,
     bump: u8,
+    gate_authority: Pubkey,
+    token_program_id: Pubkey,
+) -> Instruction {
+    ix_create_and_init_mint_with_freeze_authority(
+        program_id,
+        payer,
+        mint_pda,
+        decimals,
+        bump,
+        None,
+        gate_authority,
+        token_program_id,
+    )
+}
+
+fn ix_create_and_init_mint_with_freeze_authority(
+    program_id: Pubkey,
+    payer: Pubkey,
+    mint_pda: Pubkey,
+    decimals: u8,
+    bump: u8,
+    freeze_authority: Option<Pubkey>,
+    gate_authority: Pubkey,
+    token_program_id: Pubkey,
+) -> Instruction {
+    ix_create_and_init_mint_full(
+        program_id,
+        payer,
+        mint_pda,
+        decimals,
+        bump,
+        None,
+        freeze_authority,
+        gate_authority,
+        token_program_id,
+    )
+}
+
+fn ix_create_and_init_mint_full(
+    program_id: Pubkey,
+    payer: Pubkey,
+    mint_pda: Pubkey,
+    decimals: u8,
+    bump: u8,
+    mint_authority: Option<Pubkey>,
+    freeze_authority: Option<Pubkey>,
+    gate_authority: Pubkey,
+    token_program_id: Pubkey,
+) -> Instruction {
+    let (mint_gate, gate_bump) = find_mint_gate_pda(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),                               // payer
+            AccountMeta::new(mint_pda, false),                           // mint PDA
+            AccountMeta::new_readonly(system_program::ID, false),        // system
+            AccountMeta::new_readonly(token_program_id, false),          // token program (for CPI)
+            AccountMeta::new(mint_gate, false),                          // mint_gate PDA
+        ],
+        data: Ix::CreateAndInitMint {
+            mint_authority,
+            freeze_authority,
+            decimals,
+            bump,
+            gate_authority,
+            gate_bump,
+        }
+        .pack(),
+    }
+}
+
+fn ix_mint_to_via_program(
+    program_id: Pubkey,
+    mint_pda: Pubkey,
+    dest_ata: Pubkey,
+    gate_signer: Pubkey,
+    amount_ui: u64,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    let (mint_gate, gate_bump) = find_mint_gate_pda(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_pda, false),
+            AccountMeta::new(dest_ata, false),
+            AccountMeta::new_readonly(gate_signer, true),
+            AccountMeta::new_readonly(mint_gate, false),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+        data: Ix::MintTo { amount_ui, bump, gate_bump }.pack(),
+    }
+}
+
+fn ix_create_nft_mint(
+    program_id: Pubkey,
+    payer: Pubkey,
+    mint_pda: Pubkey,
+    bump: u8,
+    gate_authority: Pubkey,
+    token_program_id: Pubkey,
 ) -> Instruction {
+    let (mint_gate, gate_bump) = find_mint_gate_pda(&program_id);
     Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),                               // payer
             AccountMeta::new(mint_pda, false),                           // mint PDA
             AccountMeta::new_readonly(system_program::ID, false),        // system
-            AccountMeta::new_readonly(spl_token::id(), false),           // token program (for CPI)
+            AccountMeta::new_readonly(token_program_id, false),          // token program (for CPI)
+            AccountMeta::new(mint_gate, false),                          // mint_gate PDA
         ],
-        data: Ix::CreateAndInitMint { mint_authority: payer, decimals, bump }.pack(),
+        data: Ix::CreateNftMint {
+            bump,
+            gate_authority,
+            gate_bump,
+        }
+        .pack(),
+    }
+}
+
+fn ix_mint_one_and_seal(
+    program_id: Pubkey,
+    mint_pda: Pubkey,
+    dest_ata: Pubkey,
+    gate_signer: Pubkey,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    let (mint_gate, gate_bump) = find_mint_gate_pda(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_pda, false),
+            AccountMeta::new(dest_ata, false),
+            AccountMeta::new_readonly(gate_signer, true),
+            AccountMeta::new_readonly(mint_gate, false),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+        data: Ix::MintOneAndSeal { bump, gate_bump }.pack(),
+    }
+}
+
+fn ix_freeze_user_account(
+    program_id: Pubkey,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    freeze_authority: Pubkey,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    ix_freeze_user_account_full(program_id, mint, owner_ata, freeze_authority, true, bump, token_program_id)
+}
+
+/// Same as `ix_freeze_user_account`, but lets the caller mark
+/// `freeze_authority` as a non-signer — needed when it's the mint PDA,
+/// which signs via the program's own `invoke_signed`, not a wallet signature.
+fn ix_freeze_user_account_full(
+    program_id: Pubkey,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    freeze_authority: Pubkey,
+    freeze_authority_is_signer: bool,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(owner_ata, false),
+            AccountMeta::new_readonly(freeze_authority, freeze_authority_is_signer),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+        data: Ix::FreezeUserAccount { bump }.pack(),
+    }
+}
+
+fn ix_thaw_user_account(
+    program_id: Pubkey,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    freeze_authority: Pubkey,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    ix_thaw_user_account_full(program_id, mint, owner_ata, freeze_authority, true, bump, token_program_id)
+}
+
+/// Same as `ix_thaw_user_account`, but lets the caller mark
+/// `freeze_authority` as a non-signer — needed when it's the mint PDA.
+fn ix_thaw_user_account_full(
+    program_id: Pubkey,
+    mint: Pubkey,
+    owner_ata: Pubkey,
+    freeze_authority: Pubkey,
+    freeze_authority_is_signer: bool,
+    bump: u8,
+    token_program_id: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(owner_ata, false),
+            AccountMeta::new_readonly(freeze_authority, freeze_authority_is_signer),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+        data: Ix::ThawUserAccount { bump }.pack(),
     }
 }
 
@@ -73,6 +289,7 @@ fn ix_create_ata_via_program(
     owner: Pubkey,
     ata_addr: Pubkey,
     mint: Pubkey,
+    token_program_id: Pubkey,
 ) -> Instruction {
     // Your on-chain `create_ata_for` expects:
     // payer, owner, ata, mint, token_program, system_program, rent, ata_program
@@ -83,7 +300,7 @@ fn ix_create_ata_via_program(
             AccountMeta::new_readonly(owner, false),
             AccountMeta::new(ata_addr, false),
             AccountMeta::new_readonly(mint, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(ata::id(), false),
@@ -92,30 +309,13 @@ fn ix_create_ata_via_program(
     }
 }
 
-fn ix_mint_to_checked(
-    mint: Pubkey,
-    dest_ata: Pubkey,
-    mint_authority: Pubkey,
-    amount_base: u64,
-    decimals: u8,
-) -> Instruction {
-    spl_token::instruction::mint_to_checked(
-        &spl_token::id(),
-        &mint,
-        &dest_ata,
-        &mint_authority,
-        &[],
-        amount_base,
-        decimals,
-    ).unwrap()
-}
-
 fn ix_burn_ui_via_program(
     program_id: Pubkey,
     mint: Pubkey,
     owner: Pubkey,
     owner_ata: Pubkey,
     amount_ui: u64,
+    token_program_id: Pubkey,
 ) -> Instruction {
     Instruction {
         program_id,
@@ -123,12 +323,110 @@ fn ix_burn_ui_via_program(
             AccountMeta::new(mint, false),                              // ⬅️ writable mint
             AccountMeta::new(owner, true),                               // owner signer
             AccountMeta::new(owner_ata, false),                          // token account (writable ATA)
-            AccountMeta::new_readonly(spl_token::id(), false),           // token program
+            AccountMeta::new_readonly(token_program_id, false),          // token program
         ],
         data: Ix::BurnUserTokens { amount_ui }.pack(),
     }
 }
 
+fn ix_burn_ui_via_program_multisig(
+    program_id: Pubkey,
+    mint: Pubkey,
+    multisig: Pubkey,
+    owner_ata: Pubkey,
+    amount_ui: u64,
+    token_program_id: Pubkey,
+    signers: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(multisig, false),          // multisig authority, not itself a signer
+        AccountMeta::new(owner_ata, false),
+        AccountMeta::new_readonly(token_program_id, false),
+    ];
+    accounts.extend(signers.iter().map(|s| AccountMeta::new_readonly(*s, true)));
+
+    Instruction {
+        program_id,
+        accounts,
+        data: Ix::BurnUserTokens { amount_ui }.pack(),
+    }
+}
+
+
+fn ix_create_mint_with_metadata(
+    program_id: Pubkey,
+    payer: Pubkey,
+    mint_pda: Pubkey,
+    decimals: u8,
+    bump: u8,
+    metadata_program_id: Pubkey,
+    metadata_pda: Pubkey,
+    token_program_id: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),                               // payer
+            AccountMeta::new(mint_pda, false),                           // mint PDA
+            AccountMeta::new_readonly(system_program::ID, false),        // system
+            AccountMeta::new_readonly(token_program_id, false),          // token program (for CPI)
+            AccountMeta::new_readonly(metadata_program_id, false),       // Metaplex Token Metadata program
+            AccountMeta::new(metadata_pda, false),                       // metadata PDA
+            AccountMeta::new_readonly(sysvar::rent::id(), false),        // rent sysvar
+        ],
+        data: Ix::CreateMintWithMetadata { name, symbol, uri, decimals, bump }.pack(),
+    }
+}
+
+/// A minimal stand-in for the real Metaplex Token Metadata program: there is
+/// no BPF build of it available to `ProgramTest`, so this mock accepts the
+/// same account layout our `create_mint_with_metadata` CPI sends for
+/// `create_metadata_accounts_v3` and creates the metadata PDA, owned by this
+/// mock program, to prove the CPI wiring actually works end to end.
+fn mock_metadata_processor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let acc_iter = &mut accounts.iter();
+
+    let metadata_pda = next_account_info(acc_iter)?;
+    let mint = next_account_info(acc_iter)?;
+    let _mint_authority = next_account_info(acc_iter)?;
+    let payer = next_account_info(acc_iter)?;
+    let _update_authority = next_account_info(acc_iter)?;
+    let system_program = next_account_info(acc_iter)?;
+
+    let (expected_metadata_pda, bump) = Pubkey::find_program_address(
+        &[b"metadata", program_id.as_ref(), mint.key.as_ref()],
+        program_id,
+    );
+    if *metadata_pda.key != expected_metadata_pda {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let seeds: &[&[u8]] = &[b"metadata", program_id.as_ref(), mint.key.as_ref(), &[bump]];
+    let space = 1u64; // content is irrelevant here; the test only asserts ownership
+    let lamports = Rent::get()?.minimum_balance(space as usize);
+
+    invoke_signed(
+        &solana_system_interface::instruction::create_account(
+            payer.key,
+            metadata_pda.key,
+            lamports,
+            space,
+            program_id,
+        ),
+        &[payer.clone(), metadata_pda.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    Ok(())
+}
 
 // ---------- Small utils (keep borrows disjoint) ----------
 
@@ -145,14 +443,25 @@ async fn assert_mint_state(
     mint: Pubkey,
     exp_authority: Pubkey,
     exp_decimals: u8,
+    token_program_id: Pubkey,
 ) {
     let acc = ctx.banks_client.get_account(mint).await.unwrap().expect("mint exists");
-    assert_eq!(acc.owner, spl_token::id());
+    assert_eq!(acc.owner, token_program_id);
     let state = SplMint::unpack_from_slice(&acc.data).unwrap();
     assert_eq!(state.mint_authority.unwrap(), exp_authority);
     assert_eq!(state.decimals, exp_decimals);
 }
 
+async fn assert_mint_freeze_authority(
+    ctx: &mut ProgramTestContext,
+    mint: Pubkey,
+    exp_freeze_authority: Pubkey,
+) {
+    let acc = ctx.banks_client.get_account(mint).await.unwrap().expect("mint exists");
+    let state = SplMint::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.freeze_authority.unwrap(), exp_freeze_authority);
+}
+
 async fn token_amount(ctx: &mut ProgramTestContext, token_acc: Pubkey) -> u64 {
     let acc = ctx.banks_client.get_account(token_acc).await.unwrap().expect("token acc exists");
     let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
@@ -161,9 +470,8 @@ async fn token_amount(ctx: &mut ProgramTestContext, token_acc: Pubkey) -> u64 {
 
 // ---------- TEST 1: init mint ----------
 
-#[tokio::test]
-async fn test_init_mint() {
-    let (mut pt, program_id) = program_test();
+async fn run_test_init_mint(token_program_id: Pubkey) {
+    let (mut pt, program_id) = program_test(token_program_id);
     let mut ctx = pt.start_with_context().await;
 
     // Local copy of payer keypair to avoid borrowing ctx across awaits
@@ -171,22 +479,32 @@ async fn test_init_mint() {
 
     let (mint_pda, bump) = find_mint_pda(&program_id);
     let decimals = 6u8;
+    let gate_authority = Keypair::new().pubkey();
 
-    let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump);
+    let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate_authority, token_program_id);
 
     let bh = latest_blockhash(&mut ctx).await;
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer], bh);
     process(&mut ctx, tx).await;
 
-    assert_mint_state(&mut ctx, mint_pda, payer.pubkey(), decimals).await;
+    assert_mint_state(&mut ctx, mint_pda, mint_pda, decimals, token_program_id).await;
 }
 
-// ---------- TEST 2: create ATA for mint ----------
+#[tokio::test]
+async fn test_init_mint() {
+    run_test_init_mint(spl_token::id()).await;
+}
 
 #[tokio::test]
-async fn test_create_ata_for_mint() {
-    let (mut pt, program_id) = program_test();
+async fn test_init_mint_token_2022() {
+    run_test_init_mint(spl_token_2022::id()).await;
+}
+
+// ---------- TEST 2: create ATA for mint ----------
+
+async fn run_test_create_ata_for_mint(token_program_id: Pubkey) {
+    let (mut pt, program_id) = program_test(token_program_id);
     let mut ctx = pt.start_with_context().await;
 
     let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
@@ -194,8 +512,9 @@ async fn test_create_ata_for_mint() {
     // pre: mint exists
     let (mint_pda, bump) = find_mint_pda(&program_id);
     let decimals = 6u8;
+    let gate_authority = Keypair::new().pubkey();
     {
-        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump);
+        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate_authority, token_program_id);
         let bh = latest_blockhash(&mut ctx).await;
         let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
         tx.sign(&[&payer], bh);
@@ -204,9 +523,9 @@ async fn test_create_ata_for_mint() {
 
     // create ATA
     let owner = Keypair::new();
-    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &spl_token::id());
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
 
-    let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda);
+    let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
     let bh = latest_blockhash(&mut ctx).await;
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer], bh);
@@ -214,27 +533,37 @@ async fn test_create_ata_for_mint() {
 
     // assert ATA linkage
     let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
-    assert_eq!(acc.owner, spl_token::id());
+    assert_eq!(acc.owner, token_program_id);
     let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
     assert_eq!(state.mint, mint_pda);
     assert_eq!(state.owner, owner.pubkey());
     assert_eq!(state.amount, 0);
 }
 
-// ---------- TEST 3: burn tokens ----------
+#[tokio::test]
+async fn test_create_ata_for_mint() {
+    run_test_create_ata_for_mint(spl_token::id()).await;
+}
 
 #[tokio::test]
-async fn test_burn_tokens() {
-    let (mut pt, program_id) = program_test();
+async fn test_create_ata_for_mint_token_2022() {
+    run_test_create_ata_for_mint(spl_token_2022::id()).await;
+}
+
+// ---------- TEST 3: burn tokens ----------
+
+async fn run_test_burn_tokens(token_program_id: Pubkey) {
+    let (mut pt, program_id) = program_test(token_program_id);
     let mut ctx = pt.start_with_context().await;
 
     let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
 
-    // pre: mint
+    // pre: mint, captured with a freshly-generated gate authority
     let (mint_pda, bump) = find_mint_pda(&program_id);
     let decimals = 6u8;
+    let gate = Keypair::new();
     {
-        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump);
+        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate.pubkey(), token_program_id);
         let bh = latest_blockhash(&mut ctx).await;
         let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
         tx.sign(&[&payer], bh);
@@ -243,23 +572,23 @@ async fn test_burn_tokens() {
 
     // pre: ATA for owner
     let owner = Keypair::new();
-    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &spl_token::id());
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
     {
-        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda);
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
         let bh = latest_blockhash(&mut ctx).await;
         let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
         tx.sign(&[&payer], bh);
         process(&mut ctx, tx).await;
     }
 
-    // mint 5 tokens to owner's ATA
+    // mint 5 tokens to owner's ATA (the gate authority signs)
     let ui = 5u64;
     let base = ui * 10u64.pow(decimals as u32);
     {
-        let ix = ix_mint_to_checked(mint_pda, ata_addr, payer.pubkey(), base, decimals);
+        let ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, gate.pubkey(), ui, bump, token_program_id);
         let bh = latest_blockhash(&mut ctx).await;
         let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
-        tx.sign(&[&payer], bh);
+        tx.sign(&[&payer, &gate], bh);
         process(&mut ctx, tx).await;
     }
     assert_eq!(token_amount(&mut ctx, ata_addr).await, base);
@@ -267,7 +596,7 @@ async fn test_burn_tokens() {
     // burn 2 tokens via your program (owner must sign as authority)
     let burn_ui = 2u64;
     {
-        let ix = ix_burn_ui_via_program(program_id, mint_pda, owner.pubkey(), ata_addr, burn_ui);
+        let ix = ix_burn_ui_via_program(program_id, mint_pda, owner.pubkey(), ata_addr, burn_ui, token_program_id);
         let bh = latest_blockhash(&mut ctx).await;
         let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
         tx.sign(&[&payer, &owner], bh);
@@ -277,3 +606,582 @@ async fn test_burn_tokens() {
     let expected = base - burn_ui * 10u64.pow(decimals as u32);
     assert_eq!(token_amount(&mut ctx, ata_addr).await, expected);
 }
+
+#[tokio::test]
+async fn test_burn_tokens() {
+    run_test_burn_tokens(spl_token::id()).await;
+}
+
+#[tokio::test]
+async fn test_burn_tokens_token_2022() {
+    run_test_burn_tokens(spl_token_2022::id()).await;
+}
+
+// ---------- TEST 4: freeze account ----------
+
+#[tokio::test]
+async fn test_freeze_user_account() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    // mint with the payer set as freeze authority (the mint PDA stays mint authority)
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let gate_authority = Keypair::new().pubkey();
+    {
+        let ix = ix_create_and_init_mint_with_freeze_authority(
+            program_id,
+            payer.pubkey(),
+            mint_pda,
+            decimals,
+            bump,
+            Some(payer.pubkey()),
+            gate_authority,
+            token_program_id,
+        );
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    assert_mint_state(&mut ctx, mint_pda, mint_pda, decimals, token_program_id).await;
+    assert_mint_freeze_authority(&mut ctx, mint_pda, payer.pubkey()).await;
+
+    // ATA for an owner
+    let owner = Keypair::new();
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // payer (the freeze authority) freezes the account
+    {
+        let ix = ix_freeze_user_account(program_id, mint_pda, ata_addr, payer.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
+    let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.state, AccountState::Frozen);
+}
+
+// ---------- TEST 4b: thaw a previously frozen account ----------
+
+#[tokio::test]
+async fn test_thaw_user_account() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    // mint with the payer set as freeze authority (the mint PDA stays mint authority)
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let gate_authority = Keypair::new().pubkey();
+    {
+        let ix = ix_create_and_init_mint_with_freeze_authority(
+            program_id,
+            payer.pubkey(),
+            mint_pda,
+            decimals,
+            bump,
+            Some(payer.pubkey()),
+            gate_authority,
+            token_program_id,
+        );
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // ATA for an owner
+    let owner = Keypair::new();
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // payer (the freeze authority) freezes, then thaws, the account
+    {
+        let ix = ix_freeze_user_account(program_id, mint_pda, ata_addr, payer.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
+    let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.state, AccountState::Frozen);
+
+    {
+        let ix = ix_thaw_user_account(program_id, mint_pda, ata_addr, payer.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
+    let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.state, AccountState::Initialized);
+}
+
+// ---------- TEST 4c: the mint PDA itself can be the freeze authority ----------
+
+#[tokio::test]
+async fn test_freeze_user_account_with_pda_authority() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    // the mint PDA is its own freeze authority, so freezing signs via
+    // `invoke_signed` instead of a wallet signature
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let gate_authority = Keypair::new().pubkey();
+    {
+        let ix = ix_create_and_init_mint_with_freeze_authority(
+            program_id,
+            payer.pubkey(),
+            mint_pda,
+            decimals,
+            bump,
+            Some(mint_pda),
+            gate_authority,
+            token_program_id,
+        );
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    assert_mint_freeze_authority(&mut ctx, mint_pda, mint_pda).await;
+
+    // ATA for an owner
+    let owner = Keypair::new();
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // freeze, with the PDA passed as a non-signer account — the program
+    // proves it's the authority itself via `invoke_signed`
+    {
+        let ix = ix_freeze_user_account_full(program_id, mint_pda, ata_addr, mint_pda, false, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
+    let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.state, AccountState::Frozen);
+
+    // thaw, same PDA-as-authority path
+    {
+        let ix = ix_thaw_user_account_full(program_id, mint_pda, ata_addr, mint_pda, false, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let acc = ctx.banks_client.get_account(ata_addr).await.unwrap().expect("ata exists");
+    let state = SplAccount::unpack_from_slice(&acc.data).unwrap();
+    assert_eq!(state.state, AccountState::Initialized);
+}
+
+// ---------- TEST 5: mint_to requires the real gate authority ----------
+
+#[tokio::test]
+async fn test_mint_to_requires_gate_signer() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    // The gate authority is a freshly-generated key captured at mint-creation
+    // time, not a key baked into the program.
+    let gate = Keypair::new();
+    {
+        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate.pubkey(), token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let owner = Keypair::new();
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // A freshly-generated key is rejected even though it actually signs —
+    // being a signer is not enough, it must be the gate authority.
+    let impostor = Keypair::new();
+    {
+        let ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, impostor.pubkey(), 5, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &impostor], bh);
+        assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, 0);
+
+    // The real gate authority without its own signature is also rejected.
+    {
+        let mut ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, gate.pubkey(), 5, bump, token_program_id);
+        ix.accounts[2] = AccountMeta::new_readonly(gate.pubkey(), false);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, 0);
+
+    // With the real gate authority signing, minting succeeds.
+    {
+        let ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, gate.pubkey(), 5, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &gate], bh);
+        process(&mut ctx, tx).await;
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, 5 * 10u64.pow(decimals as u32));
+}
+
+// ---------- TEST 6: NFT mint and seal ----------
+
+#[tokio::test]
+async fn test_mint_one_and_seal() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let gate = Keypair::new();
+    {
+        let ix = ix_create_nft_mint(program_id, payer.pubkey(), mint_pda, bump, gate.pubkey(), token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+    assert_mint_state(&mut ctx, mint_pda, mint_pda, 0, token_program_id).await;
+
+    let owner = Keypair::new();
+    let ata_addr = ata::get_associated_token_address_with_program_id(&owner.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), owner.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // An unauthorized caller racing the legitimate recipient is rejected —
+    // signing isn't enough, the signer must be the captured gate authority.
+    let impostor = Keypair::new();
+    {
+        let ix = ix_mint_one_and_seal(program_id, mint_pda, ata_addr, impostor.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &impostor], bh);
+        assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, 0);
+
+    {
+        let ix = ix_mint_one_and_seal(program_id, mint_pda, ata_addr, gate.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &gate], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let mint_acc = ctx.banks_client.get_account(mint_pda).await.unwrap().expect("mint exists");
+    let mint_state = SplMint::unpack_from_slice(&mint_acc.data).unwrap();
+    assert_eq!(mint_state.supply, 1);
+    assert_eq!(mint_state.decimals, 0);
+    assert!(mint_state.mint_authority.is_none());
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, 1);
+
+    // A second mint attempt must fail now that the mint authority is gone.
+    {
+        let ix = ix_mint_one_and_seal(program_id, mint_pda, ata_addr, gate.pubkey(), bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &gate], bh);
+        assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+    }
+}
+
+// ---------- TEST 7: burn with a 2-of-3 multisig authority ----------
+
+#[tokio::test]
+async fn test_burn_tokens_with_multisig_authority() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let gate = Keypair::new();
+    {
+        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate.pubkey(), token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // 2-of-3 multisig
+    let multisig = Keypair::new();
+    let signer_keys: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let signer_pubkeys: Vec<Pubkey> = signer_keys.iter().map(|k| k.pubkey()).collect();
+    {
+        let rent = ctx.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(Multisig::LEN);
+        let create_ix = system_instruction::create_account(
+            &payer.pubkey(),
+            &multisig.pubkey(),
+            lamports,
+            Multisig::LEN as u64,
+            &token_program_id,
+        );
+        let init_ix = spl_token::instruction::initialize_multisig2(
+            &token_program_id,
+            &multisig.pubkey(),
+            &signer_pubkeys.iter().collect::<Vec<_>>(),
+            2,
+        ).unwrap();
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[create_ix, init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &multisig], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // ATA owned by the multisig
+    let ata_addr = ata::get_associated_token_address_with_program_id(&multisig.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), multisig.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // fund it via the program's MintTo
+    let ui = 5u64;
+    let base = ui * 10u64.pow(decimals as u32);
+    {
+        let ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, gate.pubkey(), ui, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &gate], bh);
+        process(&mut ctx, tx).await;
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, base);
+
+    // burn, signed by 2 of the 3 multisig members
+    let burn_ui = 2u64;
+    {
+        let ix = ix_burn_ui_via_program_multisig(
+            program_id,
+            mint_pda,
+            multisig.pubkey(),
+            ata_addr,
+            burn_ui,
+            token_program_id,
+            &[signer_pubkeys[0], signer_pubkeys[1]],
+        );
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &signer_keys[0], &signer_keys[1]], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    let expected = base - burn_ui * 10u64.pow(decimals as u32);
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, expected);
+}
+
+// ---------- TEST 8: a duplicated multisig signer doesn't count twice ----------
+
+#[tokio::test]
+async fn test_burn_tokens_with_multisig_authority_rejects_duplicate_signer() {
+    let token_program_id = spl_token::id();
+    let (mut pt, program_id) = program_test(token_program_id);
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let gate = Keypair::new();
+    {
+        let ix = ix_create_and_init_mint(program_id, payer.pubkey(), mint_pda, decimals, bump, gate.pubkey(), token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // 2-of-3 multisig
+    let multisig = Keypair::new();
+    let signer_keys: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let signer_pubkeys: Vec<Pubkey> = signer_keys.iter().map(|k| k.pubkey()).collect();
+    {
+        let rent = ctx.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(Multisig::LEN);
+        let create_ix = system_instruction::create_account(
+            &payer.pubkey(),
+            &multisig.pubkey(),
+            lamports,
+            Multisig::LEN as u64,
+            &token_program_id,
+        );
+        let init_ix = spl_token::instruction::initialize_multisig2(
+            &token_program_id,
+            &multisig.pubkey(),
+            &signer_pubkeys.iter().collect::<Vec<_>>(),
+            2,
+        ).unwrap();
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[create_ix, init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &multisig], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // ATA owned by the multisig
+    let ata_addr = ata::get_associated_token_address_with_program_id(&multisig.pubkey(), &mint_pda, &token_program_id);
+    {
+        let ix = ix_create_ata_via_program(program_id, payer.pubkey(), multisig.pubkey(), ata_addr, mint_pda, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], bh);
+        process(&mut ctx, tx).await;
+    }
+
+    // fund it via the program's MintTo
+    let ui = 5u64;
+    let base = ui * 10u64.pow(decimals as u32);
+    {
+        let ix = ix_mint_to_via_program(program_id, mint_pda, ata_addr, gate.pubkey(), ui, bump, token_program_id);
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &gate], bh);
+        process(&mut ctx, tx).await;
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, base);
+
+    // Only one real signer (`m` requires 2). Listing its account twice in
+    // the trailing signers must not let it count as two distinct signatures.
+    let burn_ui = 2u64;
+    {
+        let ix = ix_burn_ui_via_program_multisig(
+            program_id,
+            mint_pda,
+            multisig.pubkey(),
+            ata_addr,
+            burn_ui,
+            token_program_id,
+            &[signer_pubkeys[0], signer_pubkeys[0]],
+        );
+        let bh = latest_blockhash(&mut ctx).await;
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &signer_keys[0]], bh);
+        assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+    }
+    assert_eq!(token_amount(&mut ctx, ata_addr).await, base);
+}
+
+// ---------- TEST 9: create mint with Metaplex-style metadata ----------
+
+#[tokio::test]
+async fn test_create_mint_with_metadata() {
+    let token_program_id = spl_token::id();
+    let metadata_program_id = Pubkey::new_unique();
+
+    let (mut pt, program_id) = program_test(token_program_id);
+    pt.add_program(
+        "mock_metaplex_token_metadata",
+        metadata_program_id,
+        processor!(mock_metadata_processor),
+    );
+    let mut ctx = pt.start_with_context().await;
+
+    let payer = Keypair::from_bytes(&ctx.payer.to_bytes()).unwrap();
+
+    let (mint_pda, bump) = find_mint_pda(&program_id);
+    let decimals = 6u8;
+    let (metadata_pda, _) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program_id.as_ref(), mint_pda.as_ref()],
+        &metadata_program_id,
+    );
+
+    let ix = ix_create_mint_with_metadata(
+        program_id,
+        payer.pubkey(),
+        mint_pda,
+        decimals,
+        bump,
+        metadata_program_id,
+        metadata_pda,
+        token_program_id,
+        "Test Token".to_string(),
+        "TT".to_string(),
+        "https://example.com/metadata.json".to_string(),
+    );
+    let bh = latest_blockhash(&mut ctx).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], bh);
+    process(&mut ctx, tx).await;
+
+    assert_mint_state(&mut ctx, mint_pda, mint_pda, decimals, token_program_id).await;
+
+    let metadata_acc = ctx
+        .banks_client
+        .get_account(metadata_pda)
+        .await
+        .unwrap()
+        .expect("metadata account exists");
+    assert_eq!(metadata_acc.owner, metadata_program_id);
+}